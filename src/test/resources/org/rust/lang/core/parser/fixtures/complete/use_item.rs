@@ -18,3 +18,11 @@ use ::{*, *};
 
 use foo::{bar, {baz, quux}};
 use {crate::foo, crate::bar, super::baz};
+
+// Doubly-nested empty groups: each `{}` must parse as its own independent node rather than
+// colliding with its sibling.
+use {{}, {}};
+
+// An empty group nested alongside a real leaf: `bar::{}` must parse as its own node too,
+// independent of `Baz`.
+use foo::{bar::{}, Baz};